@@ -1,9 +1,12 @@
+use std::path::PathBuf;
+
 use bevy::{app::Events, prelude::*};
 
 use crate::{
+    library::PlantDefinition,
     plant::{
         Action, Direction, PlantBuilderComponent, PlantBundle, PlantComponent,
-        PlantRendererComponent, PlantStatsComponent, SelectedPlantsResource,
+        PlantGrowthComponent, PlantRendererComponent, PlantStatsComponent, SelectedPlantsResource,
     },
     ui::OptionsComponent,
 };
@@ -30,6 +33,48 @@ pub enum GameEvent {
         token: char,
         action: Action,
     },
+    /// Serialize an entity's plant grammar to a `*.plant.ron` file on disk.
+    SavePlant {
+        entity: Entity,
+        path: PathBuf,
+    },
+    /// Spawn a new plant from a loaded `PlantDefinition` asset, e.g. from
+    /// `PlantLibrary` or a loaded save file.
+    SpawnFromDefinition {
+        handle: Handle<PlantDefinition>,
+    },
+    /// Spawn a new plant from a `PlantDefinition` parsed directly (e.g. from
+    /// a `.plant.json` file picked with a native file dialog) rather than
+    /// loaded through the asset server.
+    SpawnFrom(PlantDefinition),
+    /// Spawn a copy of an existing plant's grammar at a new transform.
+    DuplicatePlant {
+        entity: Entity,
+        transform: Transform,
+    },
+}
+
+/// Build and spawn a `PlantBundle` from a `PlantDefinition`, matching how
+/// `generate()` builds a plant from a `PlantBuilderComponent`.
+fn spawn_from_definition(
+    cmd: &mut Commands,
+    definition: &PlantDefinition,
+    transform: Transform,
+) -> anyhow::Result<Entity> {
+    let builder = PlantBuilderComponent::from_definition(definition)?;
+    let plant = builder.generate();
+    Ok(cmd
+        .spawn_bundle(PlantBundle {
+            plant,
+            builder,
+            options: definition.options.clone(),
+            renderer: PlantRendererComponent::default(),
+            stats: PlantStatsComponent::default(),
+            growth: PlantGrowthComponent::default(),
+            transform,
+            global_transform: transform.into(),
+        })
+        .id())
 }
 
 pub(crate) fn process_events_system(
@@ -41,6 +86,7 @@ pub(crate) fn process_events_system(
         &mut PlantBuilderComponent,
         &mut PlantComponent,
     )>,
+    definitions: Res<Assets<PlantDefinition>>,
 ) {
     let mut events_buf = vec![];
     for event in events.drain() {
@@ -83,6 +129,7 @@ pub(crate) fn process_events_system(
                         options: OptionsComponent::default(),
                         renderer: PlantRendererComponent::default(),
                         stats: PlantStatsComponent::default(),
+                        growth: PlantGrowthComponent::default(),
                         transform,
                         global_transform: transform.into(),
                     })
@@ -126,6 +173,54 @@ pub(crate) fn process_events_system(
                     events_buf.push(GameEvent::TriggerUpdate(entity));
                 }
             }
+            GameEvent::SavePlant { entity, path } => {
+                if let Ok((options, builder, _)) = plants.get(entity) {
+                    let definition = builder.to_definition(options);
+                    match ron::ser::to_string_pretty(&definition, Default::default()) {
+                        Ok(ron) => {
+                            if let Err(err) = std::fs::write(&path, ron) {
+                                error!("Failed to save plant to {path:?}: {err}");
+                            }
+                        }
+                        Err(err) => error!("Failed to serialize plant: {err}"),
+                    }
+                }
+            }
+            GameEvent::SpawnFromDefinition { handle } => {
+                if let Some(definition) = definitions.get(&handle) {
+                    match spawn_from_definition(&mut cmd, definition, Transform::default()) {
+                        Ok(entity) => {
+                            events_buf.push(GameEvent::TriggerUpdate(entity));
+                            selected.0.insert(entity, ());
+                        }
+                        Err(err) => error!("Failed to spawn plant from definition: {err}"),
+                    }
+                }
+            }
+            GameEvent::SpawnFrom(definition) => {
+                match spawn_from_definition(&mut cmd, &definition, Transform::default()) {
+                    Ok(entity) => {
+                        events_buf.push(GameEvent::TriggerUpdate(entity));
+                        selected.0.insert(entity, ());
+                    }
+                    Err(err) => error!("Failed to spawn plant from definition: {err}"),
+                }
+            }
+            GameEvent::DuplicatePlant { entity, transform } => {
+                // `LSystemBuilder`/`LSystem` aren't trivially `Clone` through
+                // the public API, so rebuild from the source's token/rule/
+                // axiom data rather than deep-copying, same as `generate()`.
+                if let Ok((options, builder, _)) = plants.get(entity) {
+                    let definition = builder.to_definition(options);
+                    match spawn_from_definition(&mut cmd, &definition, transform) {
+                        Ok(clone) => {
+                            selected.0.insert(clone, ());
+                            events_buf.push(GameEvent::TriggerUpdate(clone));
+                        }
+                        Err(err) => error!("Failed to duplicate plant: {err}"),
+                    }
+                }
+            }
         }
     }
     for event in events_buf {
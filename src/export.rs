@@ -0,0 +1,261 @@
+use std::{f32::consts::TAU, path::Path};
+
+use base64::encode;
+use bevy::{
+    prelude::*,
+    render::{mesh::Indices, render_resource::PrimitiveTopology},
+};
+use serde_json::json;
+
+/// Sides of the cylinder swept along each segment. Higher values give
+/// rounder branches at the cost of more triangles.
+const TUBE_SIDES: usize = 8;
+
+/// Sweep a ring of [`TUBE_SIDES`] vertices around each segment of a plant's
+/// drawn polyline to produce a solid tube mesh, capped at the ends of each
+/// branch. Branch-break sentinels in `verts` (see
+/// [`crate::plant::PlantRendererComponent`]) split the buffer into
+/// independent tube strips, one per branch.
+pub fn build_tube_mesh(verts: &[Vec3], radius: f32) -> Mesh {
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut indices = Vec::new();
+
+    for branch in verts.split(|v| *v == Vec3::splat(f32::NEG_INFINITY)) {
+        if branch.len() >= 2 {
+            append_tube(branch, radius, &mut positions, &mut normals, &mut indices);
+        }
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.set_indices(Some(Indices::U32(indices)));
+    mesh
+}
+
+fn append_tube(
+    verts: &[Vec3],
+    radius: f32,
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    indices: &mut Vec<u32>,
+) {
+    let base = positions.len() as u32;
+
+    for (i, vert) in verts.iter().enumerate() {
+        let forward = if i + 1 < verts.len() {
+            verts[i + 1] - *vert
+        } else {
+            *vert - verts[i - 1]
+        }
+        .try_normalize()
+        .unwrap_or(Vec3::Y);
+
+        let tangent = forward.any_orthonormal_vector();
+        let bitangent = forward.cross(tangent);
+
+        for side in 0..TUBE_SIDES {
+            let angle = (side as f32 / TUBE_SIDES as f32) * TAU;
+            let offset = tangent * angle.cos() * radius + bitangent * angle.sin() * radius;
+            positions.push((*vert + offset).to_array());
+            normals.push(offset.normalize_or_zero().to_array());
+        }
+    }
+
+    for i in 0..verts.len() - 1 {
+        let ring_a = base + (i * TUBE_SIDES) as u32;
+        let ring_b = base + ((i + 1) * TUBE_SIDES) as u32;
+        for side in 0..TUBE_SIDES as u32 {
+            let next = (side + 1) % TUBE_SIDES as u32;
+            indices.extend_from_slice(&[
+                ring_a + side,
+                ring_a + next,
+                ring_b + side,
+                ring_a + next,
+                ring_b + next,
+                ring_b + side,
+            ]);
+        }
+    }
+
+    // Cap both open ends of the strip so the tube is solid rather than a
+    // hollow shell, using the same forward direction computed for each
+    // ring's radial vertices above so the cap's winding faces outward.
+    let start_forward = (verts[1] - verts[0]).try_normalize().unwrap_or(Vec3::Y);
+    let end_forward = (verts[verts.len() - 1] - verts[verts.len() - 2])
+        .try_normalize()
+        .unwrap_or(Vec3::Y);
+    append_cap(verts[0], base, -start_forward, true, positions, normals, indices);
+    append_cap(
+        verts[verts.len() - 1],
+        base + ((verts.len() - 1) * TUBE_SIDES) as u32,
+        end_forward,
+        false,
+        positions,
+        normals,
+        indices,
+    );
+}
+
+/// Fan-triangulate a flat cap over the ring of vertices `append_tube` pushed
+/// at `ring_base`, closing off one open end of a tube strip. The ring is
+/// duplicated (rather than reusing the wall vertices) so the cap can have
+/// its own flat `normal` instead of the wall's radial one; `flip_winding`
+/// reverses the fan's winding order since the start and end caps face
+/// opposite directions.
+fn append_cap(
+    center: Vec3,
+    ring_base: u32,
+    normal: Vec3,
+    flip_winding: bool,
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    indices: &mut Vec<u32>,
+) {
+    let fan_base = positions.len() as u32;
+    positions.push(center.to_array());
+    normals.push(normal.to_array());
+
+    for side in 0..TUBE_SIDES {
+        positions.push(positions[(ring_base as usize) + side]);
+        normals.push(normal.to_array());
+    }
+
+    for side in 0..TUBE_SIDES as u32 {
+        let next = (side + 1) % TUBE_SIDES as u32;
+        let (a, b) = if flip_winding {
+            (next, side)
+        } else {
+            (side, next)
+        };
+        indices.extend_from_slice(&[fan_base, fan_base + 1 + a, fan_base + 1 + b]);
+    }
+}
+
+/// Write `mesh` to `path` as Wavefront OBJ or glTF, chosen by the file
+/// extension (`.gltf`/`.glb` default to glTF, anything else is written as
+/// OBJ).
+pub fn write_mesh(mesh: &Mesh, path: &Path) -> anyhow::Result<()> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("gltf") | Some("glb") => write_gltf(mesh, path),
+        _ => write_obj(mesh, path),
+    }
+}
+
+fn mesh_positions(mesh: &Mesh) -> anyhow::Result<&[[f32; 3]]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x3(positions)) => Ok(positions),
+        _ => Err(anyhow::anyhow!("tube mesh is missing position data")),
+    }
+}
+
+fn mesh_normals(mesh: &Mesh) -> anyhow::Result<&[[f32; 3]]> {
+    match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(bevy::render::mesh::VertexAttributeValues::Float32x3(normals)) => Ok(normals),
+        _ => Err(anyhow::anyhow!("tube mesh is missing normal data")),
+    }
+}
+
+fn mesh_indices(mesh: &Mesh) -> anyhow::Result<Vec<u32>> {
+    match mesh.indices() {
+        Some(Indices::U32(indices)) => Ok(indices.clone()),
+        Some(Indices::U16(indices)) => Ok(indices.iter().map(|&i| i as u32).collect()),
+        None => Err(anyhow::anyhow!("tube mesh is missing an index buffer")),
+    }
+}
+
+fn write_obj(mesh: &Mesh, path: &Path) -> anyhow::Result<()> {
+    use std::fmt::Write;
+
+    let positions = mesh_positions(mesh)?;
+    let normals = mesh_normals(mesh)?;
+    let indices = mesh_indices(mesh)?;
+
+    let mut out = String::new();
+    writeln!(out, "# exported by bevy-planty")?;
+    for p in positions {
+        writeln!(out, "v {} {} {}", p[0], p[1], p[2])?;
+    }
+    for n in normals {
+        writeln!(out, "vn {} {} {}", n[0], n[1], n[2])?;
+    }
+    for tri in indices.chunks(3) {
+        writeln!(
+            out,
+            "f {a}//{a} {b}//{b} {c}//{c}",
+            a = tri[0] + 1,
+            b = tri[1] + 1,
+            c = tri[2] + 1,
+        )?;
+    }
+
+    std::fs::write(path, out)?;
+    Ok(())
+}
+
+/// A minimal, self-contained glTF 2.0 document: one mesh, one node, one
+/// buffer embedded as a base64 data URI.
+fn write_gltf(mesh: &Mesh, path: &Path) -> anyhow::Result<()> {
+    let positions = mesh_positions(mesh)?;
+    let normals = mesh_normals(mesh)?;
+    let indices = mesh_indices(mesh)?;
+
+    let mut buffer = Vec::new();
+    for p in positions {
+        buffer.extend(p.iter().flat_map(|c| c.to_le_bytes()));
+    }
+    let normals_offset = buffer.len();
+    for n in normals {
+        buffer.extend(n.iter().flat_map(|c| c.to_le_bytes()));
+    }
+    let indices_offset = buffer.len();
+    for i in &indices {
+        buffer.extend(i.to_le_bytes());
+    }
+
+    let (min, max) = positions.iter().fold(
+        ([f32::MAX; 3], [f32::MIN; 3]),
+        |(mut min, mut max), p| {
+            for axis in 0..3 {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+            (min, max)
+        },
+    );
+
+    let gltf = json!({
+        "asset": { "version": "2.0", "generator": "bevy-planty" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "mesh": 0 }],
+        "meshes": [{
+            "primitives": [{
+                "attributes": { "POSITION": 0, "NORMAL": 1 },
+                "indices": 2,
+                "mode": 4,
+            }],
+        }],
+        "buffers": [{
+            "byteLength": buffer.len(),
+            "uri": format!("data:application/octet-stream;base64,{}", encode(&buffer)),
+        }],
+        "bufferViews": [
+            { "buffer": 0, "byteOffset": 0, "byteLength": normals_offset },
+            { "buffer": 0, "byteOffset": normals_offset, "byteLength": indices_offset - normals_offset },
+            { "buffer": 0, "byteOffset": indices_offset, "byteLength": buffer.len() - indices_offset },
+        ],
+        "accessors": [
+            {
+                "bufferView": 0, "componentType": 5126, "count": positions.len(),
+                "type": "VEC3", "min": min, "max": max,
+            },
+            { "bufferView": 1, "componentType": 5126, "count": normals.len(), "type": "VEC3" },
+            { "bufferView": 2, "componentType": 5125, "count": indices.len(), "type": "SCALAR" },
+        ],
+    });
+
+    std::fs::write(path, serde_json::to_vec_pretty(&gltf)?)?;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use bevy::prelude::*;
+use bevy_polyline::{Polyline, PolylineBundle, PolylineMaterial};
+
+/// Renders the faint reference grid used by snap-to-grid placement; see
+/// [`crate::ui::ui_system`] for the toggle/size controls.
+pub struct GridPlugin;
+
+impl Plugin for GridPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EditorSettingsResource>()
+            .add_system(draw_grid_system);
+    }
+}
+
+/// Snap-to-grid state, kept in its own resource (rather than
+/// [`crate::ui::OptionsComponent`]) since it applies across every plant
+/// rather than belonging to one.
+pub struct EditorSettingsResource {
+    pub snap_enabled: bool,
+    pub grid_size: f32,
+}
+
+impl Default for EditorSettingsResource {
+    fn default() -> Self {
+        Self {
+            snap_enabled: false,
+            grid_size: 1f32,
+        }
+    }
+}
+
+/// Round `translation` to the nearest `grid_size` increment if snapping is
+/// enabled, otherwise return it unchanged.
+pub fn snap_to_grid(translation: Vec3, settings: &EditorSettingsResource) -> Vec3 {
+    if !settings.snap_enabled || settings.grid_size <= 0f32 {
+        return translation;
+    }
+    (translation / settings.grid_size).round() * settings.grid_size
+}
+
+/// Marker for the grid's polyline entity, so it can be rebuilt in place
+/// rather than respawned every time the settings change.
+#[derive(Component)]
+struct EditorGrid;
+
+/// How many grid increments the reference grid extends in each direction
+/// from the origin. The rendered extent (`GRID_LINE_COUNT * grid_size`)
+/// scales with `grid_size` so the drawn grid always reflects the configured
+/// snap increment, instead of a fixed world-space size that would either
+/// dwarf a small increment or barely cover a large one.
+const GRID_LINE_COUNT: i32 = 20;
+
+/// (Re)draw the ground-plane reference grid whenever snapping is toggled on
+/// or its size changes; despawn it while snapping is off.
+fn draw_grid_system(
+    mut cmd: Commands,
+    settings: Res<EditorSettingsResource>,
+    mut polylines: ResMut<Assets<Polyline>>,
+    mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
+    grid: Query<Entity, With<EditorGrid>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for entity in grid.iter() {
+        cmd.entity(entity).despawn();
+    }
+
+    if !settings.snap_enabled {
+        return;
+    }
+
+    let half_extent = GRID_LINE_COUNT as f32 * settings.grid_size;
+
+    // Branch-break sentinel between each line segment so a single Polyline
+    // can draw the whole grid. Thanks to @aevyrie as usual:
+    let mut vertices = Vec::new();
+    for i in -GRID_LINE_COUNT..=GRID_LINE_COUNT {
+        let offset = i as f32 * settings.grid_size;
+        vertices.push(Vec3::new(offset, 0f32, -half_extent));
+        vertices.push(Vec3::new(offset, 0f32, half_extent));
+        vertices.push(Vec3::splat(f32::NEG_INFINITY));
+        vertices.push(Vec3::new(-half_extent, 0f32, offset));
+        vertices.push(Vec3::new(half_extent, 0f32, offset));
+        vertices.push(Vec3::splat(f32::NEG_INFINITY));
+    }
+
+    cmd.spawn_bundle(PolylineBundle {
+        polyline: polylines.add(Polyline { vertices }),
+        material: polyline_materials.add(PolylineMaterial {
+            width: 1f32,
+            color: Color::rgba(1f32, 1f32, 1f32, 0.15),
+            perspective: true,
+        }),
+        ..Default::default()
+    })
+    .insert(EditorGrid);
+}
@@ -1,16 +1,21 @@
 use bevy::{core_pipeline::ClearColor, prelude::*, DefaultPlugins};
 use bevy_egui::EguiPlugin;
+use bevy_mod_raycast::RaycastSource;
 use bevy_polyline::PolylinePlugin;
 use events::GameEvent;
+use picking::PlantRaycastSet;
 use smooth_bevy_cameras::{
     controllers::orbit::{OrbitCameraBundle, OrbitCameraController, OrbitCameraPlugin},
     LookTransformPlugin,
 };
 
 pub mod events;
+mod export;
+mod grid;
 mod input;
+mod library;
+mod picking;
 mod plant;
-#[cfg(target_arch = "wasm32")]
 mod resize;
 mod ui;
 
@@ -27,18 +32,19 @@ impl Plugin for GamePlugin {
             .add_plugins(DefaultPlugins)
             .add_plugin(PolylinePlugin)
             .add_plugin(plant::PlantPlugin)
+            .add_plugin(library::LibraryPlugin)
+            .add_plugin(picking::PickingPlugin)
+            .add_plugin(grid::GridPlugin)
             .add_plugin(ui::UiPlugin)
             .add_plugin(EguiPlugin)
             .add_plugin(LookTransformPlugin)
             .add_plugin(OrbitCameraPlugin {
                 override_input_system: true,
             })
+            .add_plugin(resize::ViewportPlugin)
             .add_system(input::input_map_system)
             .add_system(events::process_events_system)
             .add_startup_system(setup);
-
-        #[cfg(target_arch = "wasm32")]
-        app.add_plugin(resize::ViewportPlugin);
     }
 }
 
@@ -54,17 +60,19 @@ fn setup(mut commands: Commands, mut events: EventWriter<GameEvent>) {
         ..Default::default()
     });
     // camera
-    commands.spawn_bundle(OrbitCameraBundle::new(
-        OrbitCameraController {
-            mouse_translate_sensitivity: Vec2::splat(0.016),
-            ..OrbitCameraController::default()
-        },
-        PerspectiveCameraBundle {
-            transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
-            ..Default::default()
-        },
-        Vec3::new(-10.0, 5.0, -10.0),
-        Vec3::new(0., 5., 0.),
-    ));
+    commands
+        .spawn_bundle(OrbitCameraBundle::new(
+            OrbitCameraController {
+                mouse_translate_sensitivity: Vec2::splat(0.016),
+                ..OrbitCameraController::default()
+            },
+            PerspectiveCameraBundle {
+                transform: Transform::from_xyz(-2.0, 2.5, 5.0).looking_at(Vec3::ZERO, Vec3::Y),
+                ..Default::default()
+            },
+            Vec3::new(-10.0, 5.0, -10.0),
+            Vec3::new(0., 5., 0.),
+        ))
+        .insert(RaycastSource::<PlantRaycastSet>::new());
     events.send(GameEvent::SpawnNew(Default::default()));
 }
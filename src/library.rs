@@ -0,0 +1,86 @@
+use bevy::{
+    asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset},
+    prelude::*,
+    reflect::TypeUuid,
+    utils::HashMap,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{plant::Action, ui::OptionsComponent};
+
+pub struct LibraryPlugin;
+
+impl Plugin for LibraryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<PlantDefinition>()
+            .init_asset_loader::<PlantDefinitionLoader>()
+            .init_resource::<PlantLibrary>()
+            .add_startup_system(load_library_system);
+    }
+}
+
+/// A serializable plant grammar. Round-tripped to `*.plant.ron` so presets
+/// (ferns, trees, Koch curves, ...) and a user's own creations can be saved
+/// and spawned by name instead of rebuilt by hand.
+#[derive(Clone, Serialize, Deserialize, TypeUuid)]
+#[uuid = "8c7204c1-8f92-4f8a-9c0e-8a5e7a4d0d2e"]
+pub struct PlantDefinition {
+    pub tokens: Vec<(char, Action)>,
+    pub axiom: String,
+    pub rules: Vec<String>,
+    pub options: OptionsComponent,
+}
+
+/// Catalog of on-disk plant presets, keyed by file stem (e.g. `fern` for
+/// `assets/plants/fern.plant.ron`), populated once at startup.
+#[derive(Default)]
+pub struct PlantLibrary {
+    pub presets: HashMap<String, Handle<PlantDefinition>>,
+}
+
+#[derive(Default)]
+struct PlantDefinitionLoader;
+
+impl AssetLoader for PlantDefinitionLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let definition: PlantDefinition = ron::de::from_bytes(bytes)?;
+            load_context.set_default_asset(LoadedAsset::new(definition));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["plant.ron"]
+    }
+}
+
+fn load_library_system(asset_server: Res<AssetServer>, mut library: ResMut<PlantLibrary>) {
+    let handles = match asset_server.load_folder("plants") {
+        Ok(handles) => handles,
+        Err(_) => return,
+    };
+
+    for handle in handles {
+        let handle: Handle<PlantDefinition> = handle.typed();
+        // `file_stem()` only strips the final `.ron`, leaving `fern.plant`
+        // for `fern.plant.ron`; strip the loader's whole `plant.ron`
+        // extension instead so presets are keyed the way users expect.
+        let stem = asset_server
+            .get_handle_path(&handle)
+            .and_then(|path| path.path().file_name().and_then(|n| n.to_str()).map(str::to_string))
+            .map(|name| {
+                name.strip_suffix(".plant.ron")
+                    .unwrap_or(&name)
+                    .to_string()
+            });
+
+        if let Some(stem) = stem {
+            library.presets.insert(stem, handle);
+        }
+    }
+}
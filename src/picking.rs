@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use bevy_mod_raycast::{
+    DefaultRaycastingPlugin, RaycastMesh, RaycastMethod, RaycastSource, RaycastSystem,
+};
+
+use crate::plant::{PlantRendererComponent, SelectedPlantsResource};
+
+/// Marker raycast set so plant-picking rays don't interfere with any other
+/// `bevy_mod_raycast` user in the app.
+pub struct PlantRaycastSet;
+
+pub struct PickingPlugin;
+
+impl Plugin for PickingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugin(DefaultRaycastingPlugin::<PlantRaycastSet>::default())
+            .add_system_to_stage(
+                CoreStage::First,
+                update_raycast_with_cursor.before(RaycastSystem::BuildRays::<PlantRaycastSet>),
+            )
+            .add_system(update_pick_proxy_system)
+            .add_system(
+                select_on_click_system.after(RaycastSystem::UpdateRaycast::<PlantRaycastSet>),
+            );
+    }
+}
+
+/// Links a plant entity to its invisible pickable proxy child, so the proxy
+/// can be resized in place instead of respawned on every `TriggerUpdate`.
+#[derive(Component)]
+struct PlantPickProxy(Entity);
+
+fn update_raycast_with_cursor(
+    mut cursor: EventReader<CursorMoved>,
+    mut sources: Query<&mut RaycastSource<PlantRaycastSet>>,
+) {
+    for mut source in sources.iter_mut() {
+        if let Some(cursor_latest) = cursor.iter().last() {
+            source.cast_method = RaycastMethod::Screenspace(cursor_latest.position);
+        }
+    }
+}
+
+/// Plants are drawn as `bevy_polyline` segments rather than meshes, so they
+/// have nothing for `bevy_mod_raycast` to hit. Fit an invisible box to each
+/// plant's vertex bounds and keep it as a child entity, rebuilt whenever the
+/// plant's geometry changes.
+fn update_pick_proxy_system(
+    mut cmd: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    plants: Query<
+        (Entity, &PlantRendererComponent, Option<&PlantPickProxy>),
+        Changed<PlantRendererComponent>,
+    >,
+) {
+    for (entity, renderer, proxy) in plants.iter() {
+        let (min, max) = vertex_bounds(renderer.full_verts());
+        let size = (max - min).max(Vec3::splat(0.05));
+        let center = (max + min) / 2.0;
+        let mesh = meshes.add(Mesh::from(shape::Box::new(size.x, size.y, size.z)));
+        let transform = Transform::from_translation(center);
+
+        if let Some(PlantPickProxy(proxy)) = proxy {
+            cmd.entity(*proxy).insert(mesh).insert(transform);
+        } else {
+            let proxy = cmd
+                .spawn_bundle((
+                    mesh,
+                    transform,
+                    GlobalTransform::default(),
+                    Visibility { is_visible: false },
+                    ComputedVisibility::default(),
+                    RaycastMesh::<PlantRaycastSet>::default(),
+                ))
+                .id();
+            cmd.entity(entity)
+                .add_child(proxy)
+                .insert(PlantPickProxy(proxy));
+        }
+    }
+}
+
+/// The axis-aligned bounds of a plant's real (non branch-break sentinel)
+/// vertices.
+fn vertex_bounds(verts: &[Vec3]) -> (Vec3, Vec3) {
+    verts
+        .iter()
+        .filter(|v| **v != Vec3::splat(f32::NEG_INFINITY))
+        .fold((Vec3::splat(f32::MAX), Vec3::splat(f32::MIN)), |(min, max), v| {
+            (min.min(*v), max.max(*v))
+        })
+}
+
+/// Left-click in the viewport selects the plant under the cursor;
+/// shift-click adds to the existing selection instead of replacing it.
+fn select_on_click_system(
+    mouse: Res<Input<MouseButton>>,
+    keys: Res<Input<KeyCode>>,
+    mut egui_ctx: ResMut<bevy_egui::EguiContext>,
+    sources: Query<&RaycastSource<PlantRaycastSet>>,
+    proxies: Query<&Parent, With<RaycastMesh<PlantRaycastSet>>>,
+    mut selected: ResMut<SelectedPlantsResource>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    // Without this, clicking a slider, button or text field in the settings
+    // window also registers as a (near-always-missed) viewport pick, which
+    // clears `selected` and closes the window the user is actively editing.
+    if egui_ctx.ctx_mut().wants_pointer_input() {
+        return;
+    }
+
+    let shift = keys.pressed(KeyCode::LShift) || keys.pressed(KeyCode::RShift);
+
+    let hit = sources
+        .iter()
+        .find_map(|source| source.intersect_top())
+        .and_then(|(proxy, _)| proxies.get(proxy).ok())
+        .map(|parent| parent.get());
+
+    if !shift {
+        selected.0.clear();
+    }
+
+    if let Some(entity) = hit {
+        selected.0.insert(entity, ());
+    }
+}
@@ -1,9 +1,11 @@
 use bevy::{prelude::*, utils::HashMap};
 use bevy_polyline::{Polyline, PolylineBundle, PolylineMaterial};
-use dcc_lsystem::{ArenaId, LSystem, LSystemBuilder};
+use dcc_lsystem::{ArenaId, LSystemBuilder};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 
-use crate::{events::GameEvent, ui::OptionsComponent};
+use crate::{events::GameEvent, library::PlantDefinition, ui::OptionsComponent};
 
 pub struct PlantPlugin;
 
@@ -11,22 +13,75 @@ impl Plugin for PlantPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<GameEvent>()
             .init_resource::<SelectedPlantsResource>()
-            .add_system(solver_system);
+            .add_system(solver_system)
+            .add_system(growth_system.after(solver_system));
     }
 }
 
 #[derive(Component)]
 pub struct PlantComponent {
-    pub structure: LSystem,
+    /// Raw axiom tokens. Unlike `dcc_lsystem`'s `LSystem`, nothing is
+    /// pre-expanded and cached here — `render_actions` rewrites from this
+    /// axiom every time it's called, since which alternative a stochastic
+    /// rule takes can (and should) differ from one rewrite step to the next.
+    pub axiom: Vec<char>,
+    /// Parsed `weight:successor` alternatives per token, e.g. `0.6:[+F]X`
+    /// and `0.4:F` for `X=0.6:[+F]X|0.4:F`. See
+    /// [`PlantBuilderComponent::add_rule`].
+    pub alternatives: HashMap<char, Vec<(f32, String)>>,
     pub action_map: HashMap<char, Action>,
+    /// Per-token length taper, parsed from parametric rules like
+    /// `F(l)=F(l*0.8)`. See [`PlantRendererComponent::generate_verts`].
+    pub taper_factors: HashMap<char, f32>,
 }
 
 impl PlantComponent {
-    pub fn render_actions(&self) -> Vec<Action> {
-        self.structure
-            .render()
-            .chars()
-            .map(|c| *self.action_map.get(&c).unwrap())
+    /// Rewrite the axiom `iterations` times and render the final generation
+    /// into `(token, action)` pairs. The token is kept alongside its action
+    /// so `Action::Forwards` can look up a parametric taper factor for that
+    /// specific symbol.
+    ///
+    /// `seed` reseeds the RNG used to sample stochastic alternatives, so an
+    /// unchanged seed always grows the same plant. Crucially, every
+    /// occurrence of a token at every generation samples independently here
+    /// — `dcc_lsystem`'s `LSystem` only supports a single static
+    /// `transformation_rule` per token applied uniformly for its whole
+    /// lifetime, so true per-occurrence, per-step variation has to happen
+    /// outside it.
+    ///
+    /// Each emitted token also carries its `age`: how many rewrite steps in a
+    /// row it has been re-derived from a same-character predecessor (e.g. an
+    /// `F` produced by `F(l)=F(l*0.8)` rewriting a prior `F`). A token
+    /// introduced fresh by a different predecessor (e.g. the `F` in
+    /// `X=F(l)X`) starts back at age 0. [`PlantRendererComponent::generate_verts`]
+    /// raises a tapered token's factor to this per-symbol age instead of the
+    /// overall iteration count, so only symbols that have actually persisted
+    /// and re-tapered across generations shrink — not every symbol produced
+    /// on the final rewrite step.
+    pub fn render_actions(&self, iterations: usize, seed: u64) -> Vec<(char, Action, u32)> {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut current: Vec<(char, u32)> = self.axiom.iter().map(|c| (*c, 0)).collect();
+
+        for _ in 0..iterations {
+            let mut next = Vec::with_capacity(current.len());
+            for (token, age) in &current {
+                match self.alternatives.get(token) {
+                    Some(alternatives) => {
+                        let successor = choose_alternative(alternatives, &mut rng);
+                        for child in parse_successor(successor).0 {
+                            let child_age = if child == *token { age + 1 } else { 0 };
+                            next.push((child, child_age));
+                        }
+                    }
+                    None => next.push((*token, *age)),
+                }
+            }
+            current = next;
+        }
+
+        current
+            .into_iter()
+            .map(|(c, age)| (c, *self.action_map.get(&c).unwrap(), age))
             .collect()
     }
 }
@@ -42,6 +97,166 @@ pub struct PlantStatsComponent {
 #[derive(Component, Default)]
 pub struct PlantRendererComponent {
     state: RenderState,
+    /// The fully-grown vertex buffer computed the last time the plant's
+    /// structure changed; `growth_system` progressively reveals a prefix
+    /// of this buffer rather than recomputing it every frame.
+    full_verts: Vec<Vec3>,
+}
+
+/// Drives the time-lapse reveal of a plant's vertex buffer.
+///
+/// `revealed` counts "real" (non-sentinel) vertices of
+/// [`PlantRendererComponent`]'s full buffer that have been grown so far,
+/// including a fractional part used to interpolate the partially-grown
+/// final segment.
+#[derive(Component)]
+pub struct PlantGrowthComponent {
+    pub timer: Timer,
+    /// Vertices revealed per second.
+    pub growth_rate: f32,
+    pub revealed: f32,
+}
+
+impl Default for PlantGrowthComponent {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(1f32 / 30f32, true),
+            growth_rate: 12f32,
+            revealed: 0f32,
+        }
+    }
+}
+
+impl PlantGrowthComponent {
+    /// Restart the reveal from nothing, e.g. after the plant's structure
+    /// has been regenerated.
+    pub fn reset(&mut self) {
+        self.revealed = 0f32;
+        self.timer.reset();
+    }
+}
+
+/// Is this vertex a `bevy_polyline` branch-break sentinel rather than
+/// real geometry? See the comment in [`PlantRendererComponent::generate_verts`].
+fn is_break(vert: &Vec3) -> bool {
+    *vert == Vec3::splat(f32::NEG_INFINITY)
+}
+
+/// Build the partially-grown vertex buffer: the prefix of `full_verts`
+/// containing `floor(revealed)` real vertices, with the final partially
+/// grown segment interpolated by `fract(revealed)`. Branch-break sentinels
+/// don't count towards `revealed` but are emitted once traversal reaches
+/// them, so branches pop in as growth crosses their `Push`/`Pop` point.
+fn reveal_verts(full_verts: &[Vec3], revealed: f32) -> Vec<Vec3> {
+    let target = revealed.floor().max(0f32) as usize;
+    let frac = revealed.fract();
+
+    let mut verts = Vec::with_capacity(full_verts.len());
+    let mut shown = 0usize;
+
+    for vert in full_verts {
+        if !is_break(vert) && shown >= target {
+            if frac > 0f32 {
+                // If the last emitted vertex is a branch-break sentinel,
+                // `vert` is the first real vertex of a new branch: there's
+                // nothing within the branch to interpolate from, so show it
+                // outright instead of lerping from `-inf` (which would
+                // produce `NaN`).
+                match verts.last() {
+                    Some(&prev) if !is_break(&prev) => verts.push(prev.lerp(*vert, frac)),
+                    _ => verts.push(*vert),
+                }
+            }
+            break;
+        }
+
+        verts.push(*vert);
+        if !is_break(vert) {
+            shown += 1;
+        }
+    }
+
+    verts
+}
+
+/// Split a rule's RHS into `weight:successor` alternatives separated by
+/// `|`. An alternative with no `weight:` prefix is given weight `1.0`, so a
+/// plain deterministic rule (no `|` at all) still works unchanged.
+fn parse_alternatives(rhs: &str) -> Vec<(f32, String)> {
+    rhs.split('|')
+        .map(|alt| {
+            let alt = alt.trim();
+            match alt.split_once(':') {
+                Some((weight, successor)) if weight.trim().parse::<f32>().is_ok() => {
+                    (weight.trim().parse().unwrap(), successor.trim().to_string())
+                }
+                _ => (1f32, alt.to_string()),
+            }
+        })
+        .collect()
+}
+
+/// Sample one successor from `alternatives`, weighted by their normalized
+/// weights. Falls back to an empty successor if the list is empty.
+fn choose_alternative<'a>(alternatives: &'a [(f32, String)], rng: &mut impl Rng) -> &'a str {
+    let total: f32 = alternatives.iter().map(|(weight, _)| weight).sum();
+    if total <= 0f32 {
+        return alternatives.first().map(|(_, s)| s.as_str()).unwrap_or("");
+    }
+
+    let mut sample = rng.gen_range(0f32..total);
+    for (weight, successor) in alternatives {
+        if sample < *weight {
+            return successor;
+        }
+        sample -= weight;
+    }
+    alternatives.last().map(|(_, s)| s.as_str()).unwrap_or("")
+}
+
+/// Parse a successor string into its plain token sequence and any parametric
+/// taper factors, e.g. `F(l*0.8)X` becomes `['F', 'X']` with `F` mapped to
+/// `0.8`. `dcc_lsystem` only ever expands a token to other bare tokens, so
+/// the `(...)` argument is stripped from the rule itself and instead kept
+/// alongside for [`PlantRendererComponent::generate_verts`] to consult.
+fn parse_successor(successor: &str) -> (Vec<char>, HashMap<char, f32>) {
+    let mut tokens = Vec::new();
+    let mut factors = HashMap::new();
+    let mut chars = successor.chars().peekable();
+
+    while let Some(token) = chars.next() {
+        if token.is_whitespace() {
+            continue;
+        }
+        tokens.push(token);
+
+        if chars.peek() == Some(&'(') {
+            chars.next();
+            let mut expr = String::new();
+            for inner in chars.by_ref() {
+                if inner == ')' {
+                    break;
+                }
+                expr.push(inner);
+            }
+            if let Some(factor) = parse_taper_factor(&expr) {
+                factors.insert(token, factor);
+            }
+        }
+    }
+
+    (tokens, factors)
+}
+
+/// Pull a `*factor` multiplier out of a parametric expression like
+/// `l*0.8`. Anything more elaborate than a single multiplication isn't
+/// supported, since `dcc_lsystem` has no notion of per-symbol parameters to
+/// evaluate an arbitrary expression against.
+fn parse_taper_factor(expr: &str) -> Option<f32> {
+    lazy_static::lazy_static! {
+        static ref RE: Regex = Regex::new(r"\*\s*([0-9]*\.?[0-9]+)").unwrap();
+    }
+    RE.captures(expr)?[1].parse().ok()
 }
 
 #[derive(Default)]
@@ -62,18 +277,37 @@ impl RenderState {
 }
 
 impl PlantRendererComponent {
-    pub fn generate_verts(&mut self, actions: &[Action], options: &OptionsComponent) -> Vec<Vec3> {
+    /// The fully-grown vertex buffer from the last time this plant's
+    /// structure changed, including branch-break sentinels.
+    pub fn full_verts(&self) -> &[Vec3] {
+        &self.full_verts
+    }
+
+    pub fn generate_verts(
+        &mut self,
+        actions: &[(char, Action, u32)],
+        options: &OptionsComponent,
+        taper_factors: &HashMap<char, f32>,
+    ) -> Vec<Vec3> {
         let (mut pos, mut rot) = self.state.cursor;
 
         let mut verts = vec![];
 
-        for action in actions {
+        for (token, action, age) in actions {
             match action {
                 Action::Nothing => {
                     verts.push(pos);
                 }
                 Action::Forwards => {
-                    pos += (rot * Vec3::Y) * options.segment_length;
+                    // Parametric rules like `F(l)=F(l*0.8)` taper a token's
+                    // length by `factor` per generation it has re-derived
+                    // from itself; see `age` in
+                    // [`PlantComponent::render_actions`].
+                    let length = taper_factors
+                        .get(token)
+                        .map(|factor| options.segment_length * factor.powi(*age as i32))
+                        .unwrap_or(options.segment_length);
+                    pos += (rot * Vec3::Y) * length;
                     verts.push(pos);
                 }
                 Action::Rotate(r) => {
@@ -117,6 +351,7 @@ fn solver_system(
             &mut PlantComponent,
             &mut PlantStatsComponent,
             &mut PlantRendererComponent,
+            &mut PlantGrowthComponent,
             &OptionsComponent,
             &Transform,
             &GlobalTransform,
@@ -127,15 +362,17 @@ fn solver_system(
     mut polyline_materials: ResMut<Assets<PolylineMaterial>>,
 ) {
     plants.for_each_mut(
-        |(e, mut plant, mut info, mut render, options, transform, global_transform)| {
-            plant.structure.step_by(options.iterations);
-            let instructions = plant.render_actions();
+        |(e, mut plant, mut info, mut render, mut growth, options, transform, global_transform)| {
+            let instructions = plant.render_actions(options.iterations, options.rng_seed);
 
-            let vertices: Vec<Vec3> = render.generate_verts(&instructions, options);
-            info.vert_count = vertices.len();
+            render.full_verts = render.generate_verts(&instructions, options, &plant.taper_factors);
+            info.vert_count = render.full_verts.len();
+            growth.reset();
 
             cmd.entity(e).insert_bundle(PolylineBundle {
-                polyline: polylines.add(Polyline { vertices }),
+                polyline: polylines.add(Polyline {
+                    vertices: reveal_verts(&render.full_verts, growth.revealed),
+                }),
                 material: polyline_materials.add(PolylineMaterial {
                     width: options.line_width,
                     color: Color::from(options.line_color.to_rgba_premultiplied()),
@@ -149,7 +386,37 @@ fn solver_system(
     );
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Progressively reveal each plant's vertex buffer over time, giving the
+/// appearance of growth instead of popping in fully formed.
+fn growth_system(
+    time: Res<Time>,
+    mut polylines: ResMut<Assets<Polyline>>,
+    mut plants: Query<(
+        &Handle<Polyline>,
+        &PlantRendererComponent,
+        &mut PlantGrowthComponent,
+    )>,
+) {
+    plants.for_each_mut(|(handle, render, mut growth)| {
+        let full_count = render.full_verts.iter().filter(|v| !is_break(v)).count() as f32;
+        if growth.revealed >= full_count {
+            return;
+        }
+
+        if !growth.timer.tick(time.delta()).just_finished() {
+            return;
+        }
+
+        growth.revealed = (growth.revealed + growth.growth_rate * growth.timer.duration().as_secs_f32())
+            .min(full_count);
+
+        if let Some(polyline) = polylines.get_mut(handle) {
+            polyline.vertices = reveal_verts(&render.full_verts, growth.revealed);
+        }
+    });
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Action {
     Nothing,
     Forwards,
@@ -184,7 +451,7 @@ impl std::fmt::Display for Action {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Direction {
     XPos,
     XNeg,
@@ -218,6 +485,7 @@ pub struct PlantBundle {
     pub options: OptionsComponent,
     pub renderer: PlantRendererComponent,
     pub stats: PlantStatsComponent,
+    pub growth: PlantGrowthComponent,
     pub transform: Transform,
     pub global_transform: GlobalTransform,
 }
@@ -226,10 +494,34 @@ pub struct PlantBundle {
 pub struct PlantBuilderComponent {
     builder: LSystemBuilder,
     tokens: HashMap<char, (ArenaId, Action)>,
+    /// Raw axiom tokens, copied verbatim into the generated
+    /// [`PlantComponent`]. See [`PlantComponent::render_actions`] for why
+    /// rewriting happens there instead of through `dcc_lsystem`.
+    axiom: Vec<char>,
+    /// Parsed `weight:successor` alternatives per LHS token.
+    alternatives: HashMap<char, Vec<(f32, String)>>,
+    /// Per-token length multipliers parsed from parametric successors such as
+    /// `F(l*0.8)`, carried through to the generated [`PlantComponent`].
+    taper_factors: HashMap<char, f32>,
 }
 
 impl PlantBuilderComponent {
-    /// Add a transformation rule to the builder.
+    /// Parse a transformation rule's alternatives and validate that every
+    /// token they mention is registered, without applying the rule yet.
+    ///
+    /// The RHS may list several `weight:successor` alternatives separated by
+    /// `|` (e.g. `X=0.6:[+F]X|0.4:F`). Unlike `dcc_lsystem`'s
+    /// `transformation_rule`, which bakes a single static successor into the
+    /// `LSystem` for its whole lifetime, the alternatives are kept as-is and
+    /// [`PlantComponent::render_actions`] samples one independently for
+    /// every occurrence of the token at every rewrite step — that
+    /// per-occurrence, per-step variation is the entire point of a
+    /// stochastic L-system and `dcc_lsystem` has no way to express it on its
+    /// own. A successor token may also carry a parenthesized parametric
+    /// expression (e.g. `F(l*0.8)`); only a `*factor` multiplier is
+    /// understood, and it's recorded in `taper_factors` for
+    /// [`PlantRendererComponent::generate_verts`] to apply, since the token
+    /// itself still only expands to a plain character.
     /// Panics if a necessary token is not found
     pub fn add_rule<S>(&mut self, rule: S) -> anyhow::Result<&mut Self>
     where
@@ -238,7 +530,7 @@ impl PlantBuilderComponent {
         let rule = rule.as_ref();
 
         lazy_static::lazy_static! {
-            static ref RE: Regex = Regex::new(r"\s*(\w)\s*=\s*((?:\s*\S+\s*)*)\s*").unwrap();
+            static ref RE: Regex = Regex::new(r"^\s*(\w)(?:\([^)]*\))?\s*=\s*(.+?)\s*$").unwrap();
         }
 
         let cap = RE
@@ -246,18 +538,22 @@ impl PlantBuilderComponent {
             .ok_or_else(|| anyhow::anyhow!("Captures error: {rule}"))?;
 
         // The LHS of our rule
-        let lhs = self.get_token(cap[1].chars().next().unwrap())?.0;
-
-        // Construct the RHS of our rule
-        let mut rule = Vec::new();
-
-        for token in cap[2].chars() {
-            let token = self.get_token(token)?;
-            rule.push(token.0);
+        let lhs = cap[1].chars().next().unwrap();
+        self.get_token(lhs)?;
+
+        // Validate every alternative's tokens up front and collect any
+        // parametric taper factors; which one actually gets used is decided
+        // independently at every rewrite step, not here.
+        let alternatives = parse_alternatives(&cap[2]);
+        for (_, successor) in &alternatives {
+            let (tokens, factors) = parse_successor(successor);
+            for token in tokens {
+                self.get_token(token)?;
+            }
+            self.taper_factors.extend(factors);
         }
 
-        // Add the rule to our builder
-        self.builder.transformation_rule(lhs, rule).ok();
+        self.alternatives.insert(lhs, alternatives);
         Ok(self)
     }
 
@@ -265,7 +561,8 @@ impl PlantBuilderComponent {
     where
         S: AsRef<str>,
     {
-        self.builder.rules.clear();
+        self.alternatives.clear();
+        self.taper_factors.clear();
         for rule in rules {
             self.add_rule(rule)?;
         }
@@ -292,12 +589,11 @@ impl PlantBuilderComponent {
     }
 
     pub fn set_axiom(&mut self, tokens: impl AsRef<str>) -> anyhow::Result<&mut Self> {
-        let tokens: Vec<ArenaId> = tokens
+        self.axiom = tokens
             .as_ref()
             .chars()
-            .filter_map(|token| self.get_token(token).map(|(id, _)| id).ok())
+            .filter(|token| self.get_token(*token).is_ok())
             .collect();
-        self.builder.axiom(tokens).ok();
         Ok(self)
     }
 
@@ -309,10 +605,128 @@ impl PlantBuilderComponent {
     }
 
     pub fn generate(&self) -> PlantComponent {
-        let f = self.builder.clone();
         PlantComponent {
-            structure: f.finish().unwrap(),
+            axiom: self.axiom.clone(),
+            alternatives: self.alternatives.clone(),
             action_map: self.tokens.iter().map(|(c, (_, a))| (*c, *a)).collect(),
+            taper_factors: self.taper_factors.clone(),
         }
     }
+
+    /// Rebuild a builder from a serialized [`PlantDefinition`].
+    pub fn from_definition(definition: &PlantDefinition) -> anyhow::Result<Self> {
+        let mut builder = Self::default();
+        builder.set_tokens(&definition.tokens);
+        builder.set_axiom(&definition.axiom)?;
+        builder.set_rules(&definition.rules)?;
+        Ok(builder)
+    }
+
+    /// Export this builder's tokens, alongside the axiom/rules/options
+    /// currently driving it, to a serializable [`PlantDefinition`].
+    pub fn to_definition(&self, options: &OptionsComponent) -> PlantDefinition {
+        PlantDefinition {
+            tokens: self.tokens.iter().map(|(c, (_, a))| (*c, *a)).collect(),
+            axiom: options.axiom.clone(),
+            rules: options.rules.clone(),
+            options: options.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_alternatives_defaults_unweighted_to_one() {
+        assert_eq!(parse_alternatives("FX"), vec![(1f32, "FX".to_string())]);
+    }
+
+    #[test]
+    fn parse_alternatives_splits_weighted_successors() {
+        assert_eq!(
+            parse_alternatives("0.6:[+F]X|0.4:F"),
+            vec![(0.6, "[+F]X".to_string()), (0.4, "F".to_string())]
+        );
+    }
+
+    #[test]
+    fn choose_alternative_picks_by_weighted_range() {
+        let alternatives = vec![(1f32, "a".to_string()), (1f32, "b".to_string())];
+        let mut rng = StdRng::seed_from_u64(0);
+        let picks: Vec<&str> = (0..20)
+            .map(|_| choose_alternative(&alternatives, &mut rng))
+            .collect();
+        assert!(picks.contains(&"a"));
+        assert!(picks.contains(&"b"));
+    }
+
+    #[test]
+    fn choose_alternative_same_seed_is_deterministic() {
+        let alternatives = vec![(0.5, "a".to_string()), (0.5, "b".to_string())];
+        let mut rng_a = StdRng::seed_from_u64(42);
+        let mut rng_b = StdRng::seed_from_u64(42);
+        let picks_a: Vec<&str> = (0..10)
+            .map(|_| choose_alternative(&alternatives, &mut rng_a))
+            .collect();
+        let picks_b: Vec<&str> = (0..10)
+            .map(|_| choose_alternative(&alternatives, &mut rng_b))
+            .collect();
+        assert_eq!(picks_a, picks_b);
+    }
+
+    #[test]
+    fn reveal_verts_does_not_lerp_from_a_branch_break_sentinel() {
+        let full_verts = vec![
+            Vec3::ZERO,
+            Vec3::splat(f32::NEG_INFINITY),
+            Vec3::new(1f32, 0f32, 0f32),
+            Vec3::new(2f32, 0f32, 0f32),
+        ];
+
+        // `revealed` stops partway into the branch right after the sentinel,
+        // so the fractional vertex has nothing real within the branch to
+        // interpolate from.
+        let verts = reveal_verts(&full_verts, 1.5);
+
+        assert!(verts.iter().all(|v| !v.is_nan()));
+        assert_eq!(verts.last(), Some(&Vec3::new(1f32, 0f32, 0f32)));
+    }
+
+    #[test]
+    fn render_actions_ages_tapered_tokens_by_generations_survived_not_total_iterations() {
+        let mut builder = PlantBuilderComponent::default();
+        builder
+            .set_tokens(&[('X', Action::Nothing), ('F', Action::Forwards)])
+            .set_axiom("X")
+            .unwrap();
+        builder
+            .set_rules(&["X=F(l)X", "F(l)=F(l*0.8)"])
+            .unwrap();
+        let plant = builder.generate();
+
+        let ages: Vec<u32> = plant
+            .render_actions(3, 0)
+            .into_iter()
+            .filter(|(token, ..)| *token == 'F')
+            .map(|(_, _, age)| age)
+            .collect();
+
+        // Axiom X -> [F X] -> [F X] -> [F X] over 3 iterations produces one
+        // fresh F per generation: the oldest has re-tapered via F's own rule
+        // twice, the newest hasn't re-tapered at all yet.
+        assert_eq!(ages, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn reveal_verts_lerps_within_a_branch() {
+        let full_verts = vec![
+            Vec3::ZERO,
+            Vec3::new(2f32, 0f32, 0f32),
+            Vec3::new(4f32, 0f32, 0f32),
+        ];
+        let verts = reveal_verts(&full_verts, 1.5);
+        assert_eq!(verts.last(), Some(&Vec3::new(1f32, 0f32, 0f32)));
+    }
 }
@@ -23,43 +23,49 @@
 // SOFTWARE.
 
 use bevy::prelude::*;
-use futures::channel::mpsc;
-use gloo_events;
-use wasm_bindgen::prelude::*;
 
+pub struct ViewportPlugin;
+
+/// On native, the windowing backend (winit) already updates `Windows` with
+/// the new size before `WindowResized` fires, so there's nothing for this
+/// plugin to do there. It exists only to work around wasm having no native
+/// window to read a resize from — see the `web` module below.
+impl Plugin for ViewportPlugin {
+    fn build(&self, #[allow(unused_variables)] app: &mut App) {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use futures::channel::mpsc;
+
+            let (sender, receiver) = mpsc::unbounded();
+            web::listen(sender);
+            app.insert_resource(ViewportState { receiver })
+                .add_system(resized_event_system)
+                .add_startup_system(web::initial_size_system);
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
 #[derive(Debug)]
 struct ViewportResized {
     width: f32,
     height: f32,
 }
 
-#[derive(Debug)]
-struct ViewportState {
-    receiver: mpsc::UnboundedReceiver<ViewportResized>,
-}
-
-pub struct ViewportPlugin;
-
+#[cfg(target_arch = "wasm32")]
 impl From<(f32, f32)> for ViewportResized {
     fn from((width, height): (f32, f32)) -> Self {
         ViewportResized { width, height }
     }
 }
 
-fn get_viewport_size() -> (f32, f32) {
-    let window = web_sys::window().expect("could not get window");
-    let document_element = window
-        .document()
-        .expect("could not get document")
-        .document_element()
-        .expect("could not get document element");
-
-    (
-        document_element.client_width() as f32,
-        document_element.client_height() as f32,
-    )
+#[cfg(target_arch = "wasm32")]
+#[derive(Debug)]
+struct ViewportState {
+    receiver: futures::channel::mpsc::UnboundedReceiver<ViewportResized>,
 }
 
+#[cfg(target_arch = "wasm32")]
 fn resized_event_system(mut windows: ResMut<Windows>, mut state: ResMut<ViewportState>) {
     if let Ok(Some(event)) = state.receiver.try_next() {
         if let Some(window) = windows.get_primary_mut() {
@@ -68,26 +74,43 @@ fn resized_event_system(mut windows: ResMut<Windows>, mut state: ResMut<Viewport
     }
 }
 
-fn initial_size_system(mut windows: ResMut<Windows>) {
-    let (width, height) = get_viewport_size();
-    if let Some(window) = windows.get_primary_mut() {
-        window.set_resolution(width, height);
+#[cfg(target_arch = "wasm32")]
+mod web {
+    use bevy::prelude::*;
+    use futures::channel::mpsc;
+    use gloo_events::EventListener;
+    use wasm_bindgen::prelude::*;
+
+    use super::ViewportResized;
+
+    pub fn get_viewport_size() -> (f32, f32) {
+        let window = web_sys::window().expect("could not get window");
+        let document_element = window
+            .document()
+            .expect("could not get document")
+            .document_element()
+            .expect("could not get document element");
+
+        (
+            document_element.client_width() as f32,
+            document_element.client_height() as f32,
+        )
     }
-}
 
-impl Plugin for ViewportPlugin {
-    fn build(&self, app: &mut App) {
-        let (sender, receiver) = mpsc::unbounded();
+    pub fn listen(sender: mpsc::UnboundedSender<ViewportResized>) {
         let window = web_sys::window().expect("could not get window");
-        gloo_events::EventListener::new(&window, "resize", move |_event| {
+        EventListener::new(&window, "resize", move |_event| {
             sender
                 .unbounded_send(get_viewport_size().into())
                 .unwrap_throw();
         })
         .forget();
+    }
 
-        app.insert_resource(ViewportState { receiver })
-            .add_system(resized_event_system)
-            .add_startup_system(initial_size_system);
+    pub fn initial_size_system(mut windows: ResMut<Windows>) {
+        let (width, height) = get_viewport_size();
+        if let Some(window) = windows.get_primary_mut() {
+            window.set_resolution(width, height);
+        }
     }
 }
@@ -1,15 +1,124 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, utils::HashMap};
 use bevy_egui::egui::{self, color::Hsva};
+use serde::{Deserialize, Serialize};
 
 use crate::{
     events::GameEvent,
+    grid::{snap_to_grid, EditorSettingsResource},
+    library::PlantLibrary,
     plant::{self, *},
 };
 pub struct UiPlugin;
 
 impl Plugin for UiPlugin {
     fn build(&self, app: &mut bevy::prelude::App) {
-        app.add_system(ui_system);
+        app.init_resource::<UndoStack>()
+            .add_system(ui_system)
+            .add_system(undo_redo_system.before(ui_system));
+    }
+}
+
+/// A snapshot of the editable parts of a plant, used to step back/forward
+/// through edits.
+#[derive(Clone)]
+struct PlantSnapshot {
+    options: OptionsComponent,
+    action_map: HashMap<char, Action>,
+}
+
+/// Per-entity undo/redo history for plant parameter and rule edits.
+///
+/// Snapshots are pushed before a change is applied, so popping one restores
+/// the state immediately prior to that change. Rapid slider drags are
+/// coalesced into a single entry by only snapshotting when a widget gains
+/// focus rather than every frame it changes.
+#[derive(Default)]
+pub struct UndoStack {
+    undo: HashMap<Entity, Vec<PlantSnapshot>>,
+    redo: HashMap<Entity, Vec<PlantSnapshot>>,
+}
+
+impl UndoStack {
+    fn push(&mut self, entity: Entity, options: &OptionsComponent, plant: &PlantComponent) {
+        self.undo.entry(entity).or_default().push(PlantSnapshot {
+            options: options.clone(),
+            action_map: plant.action_map.clone(),
+        });
+        self.redo.entry(entity).or_default().clear();
+    }
+
+    fn undo(
+        &mut self,
+        entity: Entity,
+        options: &OptionsComponent,
+        plant: &PlantComponent,
+    ) -> Option<PlantSnapshot> {
+        let snapshot = self.undo.get_mut(&entity)?.pop()?;
+        self.redo.entry(entity).or_default().push(PlantSnapshot {
+            options: options.clone(),
+            action_map: plant.action_map.clone(),
+        });
+        Some(snapshot)
+    }
+
+    fn redo(
+        &mut self,
+        entity: Entity,
+        options: &OptionsComponent,
+        plant: &PlantComponent,
+    ) -> Option<PlantSnapshot> {
+        let snapshot = self.redo.get_mut(&entity)?.pop()?;
+        self.undo.entry(entity).or_default().push(PlantSnapshot {
+            options: options.clone(),
+            action_map: plant.action_map.clone(),
+        });
+        Some(snapshot)
+    }
+}
+
+/// Binds Ctrl+Z/Ctrl+Y to step the selected plants' [`UndoStack`] back and
+/// forward.
+fn undo_redo_system(
+    keys: Res<Input<KeyCode>>,
+    mut undo_stack: ResMut<UndoStack>,
+    mut plants: Query<(
+        &mut OptionsComponent,
+        &mut PlantComponent,
+        &mut PlantBuilderComponent,
+    )>,
+    selected: Res<SelectedPlantsResource>,
+    mut events: EventWriter<GameEvent>,
+) {
+    let ctrl = keys.pressed(KeyCode::LControl) || keys.pressed(KeyCode::RControl);
+    if !ctrl {
+        return;
+    }
+
+    let undo_pressed = keys.just_pressed(KeyCode::Z);
+    let redo_pressed = keys.just_pressed(KeyCode::Y);
+    if !undo_pressed && !redo_pressed {
+        return;
+    }
+
+    for (entity, _) in selected.0.iter() {
+        if let Ok((mut options, mut plant, mut builder)) = plants.get_mut(*entity) {
+            let snapshot = if undo_pressed {
+                undo_stack.undo(*entity, &options, &plant)
+            } else {
+                undo_stack.redo(*entity, &options, &plant)
+            };
+
+            if let Some(snapshot) = snapshot {
+                *options = snapshot.options;
+                // `TriggerUpdate` regenerates the plant from the builder's
+                // tokens, so restore the snapshot's actions there as well.
+                let tokens: Vec<(char, Action)> =
+                    snapshot.action_map.iter().map(|(c, a)| (*c, *a)).collect();
+                builder.set_tokens(&tokens);
+                plant.action_map = snapshot.action_map;
+                events.send(GameEvent::TriggerUpdate(*entity));
+            }
+        }
     }
 }
 
@@ -20,30 +129,79 @@ fn ui_system(
         &mut OptionsComponent,
         &PlantStatsComponent,
         &PlantComponent,
+        &PlantBuilderComponent,
+        &PlantRendererComponent,
         &mut Transform,
     )>,
     mut events: EventWriter<GameEvent>,
     mut selected: ResMut<SelectedPlantsResource>,
     mut offset: Local<Vec3>,
+    mut undo_stack: ResMut<UndoStack>,
+    mut editor_settings: ResMut<EditorSettingsResource>,
+    library: Res<PlantLibrary>,
 ) {
     egui::TopBottomPanel::bottom("info").show(ctx.ctx_mut(), |ui| {
         ui.horizontal(|ui| {
             if ui.button("Add plant").clicked() {
                 *offset = *offset + Vec3::X * 2f32;
-                events.send(GameEvent::SpawnNew(Transform::from_translation(*offset)));
+                let translation = snap_to_grid(*offset, &editor_settings);
+                events.send(GameEvent::SpawnNew(Transform::from_translation(translation)));
+            }
+
+            ui.separator();
+
+            let mut snap_enabled = editor_settings.snap_enabled;
+            let mut grid_size = editor_settings.grid_size;
+            let snap_toggle = ui.checkbox(&mut snap_enabled, "Snap to grid");
+            let grid_size_drag = ui.add_enabled(
+                snap_enabled,
+                egui::DragValue::new(&mut grid_size)
+                    .prefix("Grid size: ")
+                    .clamp_range(0.1..=100f32)
+                    .speed(0.1),
+            );
+            if snap_toggle.changed() || grid_size_drag.changed() {
+                editor_settings.snap_enabled = snap_enabled;
+                editor_settings.grid_size = grid_size;
             }
 
             ui.separator();
 
-            plants.for_each(|(e, ..)| {
-                if ui.button(format!("Plant {}", e.id() - 1)).clicked() {
-                    selected.0.insert(e, ());
+            if ui.button("Save plant").clicked() {
+                if let Some(entity) = selected.0.keys().next().copied() {
+                    if let Ok((_, options, _, _, builder, _, _)) = plants.get(entity) {
+                        save_plant(&builder.to_definition(&options));
+                    }
                 }
-            });
+            }
+
+            if ui.button("Load plant").clicked() {
+                if let Some(definition) = load_plant() {
+                    events.send(GameEvent::SpawnFrom(definition));
+                }
+            }
+
+            ui.separator();
+
+            let mut preset_names: Vec<&String> = library.presets.keys().collect();
+            preset_names.sort();
+            egui::ComboBox::from_label("Spawn preset")
+                .selected_text("Choose a preset...")
+                .show_ui(ui, |ui| {
+                    for name in preset_names {
+                        if ui.selectable_label(false, name).clicked() {
+                            events.send(GameEvent::SpawnFromDefinition {
+                                handle: library.presets[name].clone(),
+                            });
+                        }
+                    }
+                });
         });
         ui.separator();
         ui.vertical(|ui| {
             ui.horizontal(|ui| {
+                ui.label("Select: Left click a plant");
+                ui.separator();
                 ui.label("Rotate: Middle click and drag");
                 ui.separator();
                 ui.label("Pan: Right click and drag");
@@ -55,7 +213,7 @@ fn ui_system(
 
     let mut i = 0;
     plants.for_each_mut(
-        |(entity, mut values, PlantStatsComponent { vert_count }, plant, mut transform)| {
+        |(entity, mut values, PlantStatsComponent { vert_count }, plant, _builder, renderer, mut transform)| {
             i += 1;
             let is_selected = selected.0.iter().any(|(x, _)| *x == entity);
             let mut window_is_open = is_selected;
@@ -66,9 +224,28 @@ fn ui_system(
                 .show(ctx.ctx_mut(), |ui| {
                     ui.label(format!("Total verticies: {vert_count}"));
 
+                    if ui.button("Export model").clicked() {
+                        export_model(renderer, &values);
+                    }
+
+                    if ui.button("Duplicate plant").clicked() {
+                        let mut duplicate_transform = *transform;
+                        duplicate_transform.translation = snap_to_grid(
+                            transform.translation + Vec3::X * 2f32,
+                            &editor_settings,
+                        );
+                        events.send(GameEvent::DuplicatePlant {
+                            entity,
+                            transform: duplicate_transform,
+                        });
+                    }
+
                     ui.collapsing("Settings", |ui| {
                         ui.label("Line color");
                         let color = ui.color_edit_button_hsva(&mut values.line_color);
+                        if color.gained_focus() {
+                            undo_stack.push(entity, &values, plant);
+                        }
 
                         ui.separator();
 
@@ -78,29 +255,43 @@ fn ui_system(
                                 .smart_aim(false)
                                 .max_decimals(2),
                         );
+                        if width.gained_focus() {
+                            undo_stack.push(entity, &values, plant);
+                        }
 
                         ui.separator();
 
                         ui.label("Transform");
                         let mut translation: Vec3 = transform.translation.clone();
+                        let mut translation_changed = false;
                         ui.horizontal(|ui| {
-                            ui.add(
-                                egui::DragValue::new(&mut translation.x)
-                                    .prefix("x: ")
-                                    .min_decimals(2),
-                            );
-                            ui.add(
-                                egui::DragValue::new(&mut translation.y)
-                                    .prefix("y: ")
-                                    .min_decimals(2),
-                            );
-                            ui.add(
-                                egui::DragValue::new(&mut translation.z)
-                                    .prefix("z: ")
-                                    .min_decimals(2),
-                            );
+                            translation_changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut translation.x)
+                                        .prefix("x: ")
+                                        .min_decimals(2),
+                                )
+                                .changed();
+                            translation_changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut translation.y)
+                                        .prefix("y: ")
+                                        .min_decimals(2),
+                                )
+                                .changed();
+                            translation_changed |= ui
+                                .add(
+                                    egui::DragValue::new(&mut translation.z)
+                                        .prefix("z: ")
+                                        .min_decimals(2),
+                                )
+                                .changed();
                         });
-                        transform.translation = translation;
+                        transform.translation = if translation_changed {
+                            snap_to_grid(translation, &editor_settings)
+                        } else {
+                            translation
+                        };
 
                         ui.separator();
 
@@ -109,6 +300,9 @@ fn ui_system(
                         ui.label("Iterations");
 
                         let iterations = ui.add(egui::Slider::new(&mut values.iterations, 1..=10));
+                        if iterations.gained_focus() {
+                            undo_stack.push(entity, &values, plant);
+                        }
 
                         ui.separator();
 
@@ -118,6 +312,9 @@ fn ui_system(
                                 .max_decimals(2)
                                 .smart_aim(false),
                         );
+                        if rot_angle.gained_focus() {
+                            undo_stack.push(entity, &values, plant);
+                        }
 
                         ui.separator();
 
@@ -126,6 +323,9 @@ fn ui_system(
                             &mut values.segment_length,
                             0.01f32..=1.0f32,
                         ));
+                        if segment_length.gained_focus() {
+                            undo_stack.push(entity, &values, plant);
+                        }
 
                         if rot_angle.changed()
                             || segment_length.changed()
@@ -149,6 +349,9 @@ fn ui_system(
                                     .desired_rows(1)
                                     .desired_width(f32::INFINITY),
                             );
+                            if axiom.gained_focus() {
+                                undo_stack.push(entity, &values, plant);
+                            }
 
                             ui.separator();
 
@@ -164,8 +367,12 @@ fn ui_system(
                                             .desired_rows(1)
                                             .desired_width(f32::INFINITY),
                                     );
+                                    if text.gained_focus() {
+                                        undo_stack.push(entity, &values, plant);
+                                    }
                                     let remove_button = ui.button("Remove rule");
                                     if remove_button.clicked() {
+                                        undo_stack.push(entity, &values, plant);
                                         to_remove.push(i);
                                     }
                                     if text.changed() || remove_button.clicked() {
@@ -181,10 +388,31 @@ fn ui_system(
                             let add_rule = ui.button("Add rule");
 
                             if add_rule.clicked() {
+                                undo_stack.push(entity, &values, plant);
                                 values.rules.push(Default::default());
                             };
 
-                            if add_rule.clicked() || axiom.changed() || rule_changed {
+                            ui.separator();
+
+                            ui.label("Seed");
+                            let mut seed_changed = false;
+                            ui.horizontal(|ui| {
+                                let seed = ui.add(egui::DragValue::new(&mut values.rng_seed));
+                                if seed.gained_focus() {
+                                    undo_stack.push(entity, &values, plant);
+                                }
+                                seed_changed |= seed.changed();
+
+                                let reroll = ui.button("Reroll");
+                                if reroll.clicked() {
+                                    undo_stack.push(entity, &values, plant);
+                                    values.rng_seed = rand::random();
+                                    seed_changed = true;
+                                }
+                            });
+
+                            if add_rule.clicked() || axiom.changed() || rule_changed || seed_changed
+                            {
                                 events.send(GameEvent::TriggerUpdate(entity))
                             }
                         });
@@ -202,6 +430,7 @@ fn ui_system(
 
                                 if token_edit.changed() && !token_text.is_empty() {
                                     let next = token_text.chars().next().unwrap_or(**token);
+                                    undo_stack.push(entity, &values, plant);
                                     events.send(GameEvent::ChangeToken {
                                         entity,
                                         prev: **token,
@@ -223,6 +452,7 @@ fn ui_system(
                                                 )
                                                 .clicked()
                                             {
+                                                undo_stack.push(entity, &values, plant);
                                                 events.send(GameEvent::ChangeAction {
                                                     entity,
                                                     token: **token,
@@ -263,6 +493,7 @@ fn ui_system(
                                     .response;
 
                                 if ui.button("Remove").clicked() {
+                                    undo_stack.push(entity, &values, plant);
                                     events.send(GameEvent::RemoveToken {
                                         entity,
                                         token: **token,
@@ -272,6 +503,7 @@ fn ui_system(
                             }
                         });
                         if ui.button("Add symbol").clicked() {
+                            undo_stack.push(entity, &values, plant);
                             events.send(GameEvent::AddToken {
                                 entity,
                                 token: '~',
@@ -289,7 +521,68 @@ fn ui_system(
     );
 }
 
-#[derive(Component)]
+/// Line width is tuned as a screen-space polyline width (0.1..=500), so
+/// scale it down to a plausible world-space tube radius.
+const LINE_WIDTH_TO_RADIUS: f32 = 0.002;
+
+/// Prompt for a model save location and export the plant's drawn segments
+/// as a solid tube mesh, in glTF or OBJ depending on the chosen extension.
+fn export_model(renderer: &PlantRendererComponent, options: &OptionsComponent) {
+    let path = match rfd::FileDialog::new()
+        .add_filter("glTF", &["gltf"])
+        .add_filter("OBJ", &["obj"])
+        .set_file_name("plant.gltf")
+        .save_file()
+    {
+        Some(path) => path,
+        None => return,
+    };
+
+    let radius = (options.line_width * LINE_WIDTH_TO_RADIUS).max(0.001);
+    let mesh = crate::export::build_tube_mesh(renderer.full_verts(), radius);
+
+    if let Err(err) = crate::export::write_mesh(&mesh, &path) {
+        error!("Failed to export plant model to {path:?}: {err}");
+    }
+}
+
+/// Prompt for a `.plant.json` save location and write `definition` to it.
+fn save_plant(definition: &crate::library::PlantDefinition) {
+    let path = match rfd::FileDialog::new()
+        .add_filter("plant", &["plant.json"])
+        .set_file_name("plant.json")
+        .save_file()
+    {
+        Some(path) => path,
+        None => return,
+    };
+
+    match serde_json::to_string_pretty(definition) {
+        Ok(json) => {
+            if let Err(err) = std::fs::write(&path, json) {
+                error!("Failed to save plant to {path:?}: {err}");
+            }
+        }
+        Err(err) => error!("Failed to serialize plant: {err}"),
+    }
+}
+
+/// Prompt for a `.plant.json` file and parse it into a [`PlantDefinition`](crate::library::PlantDefinition).
+fn load_plant() -> Option<crate::library::PlantDefinition> {
+    let path = rfd::FileDialog::new()
+        .add_filter("plant", &["plant.json"])
+        .pick_file()?;
+
+    let json = std::fs::read_to_string(&path)
+        .map_err(|err| error!("Failed to read {path:?}: {err}"))
+        .ok()?;
+
+    serde_json::from_str(&json)
+        .map_err(|err| error!("Failed to parse {path:?}: {err}"))
+        .ok()
+}
+
+#[derive(Component, Clone, Serialize, Deserialize)]
 pub struct OptionsComponent {
     pub rotation_amount: f32,
     pub segment_length: f32,
@@ -297,7 +590,34 @@ pub struct OptionsComponent {
     pub axiom: String,
     pub iterations: usize,
     pub line_width: f32,
+    #[serde(with = "hsva_serde")]
     pub line_color: Hsva,
+    /// Seeds the RNG used to sample stochastic rule alternatives, so a given
+    /// seed always grows the same plant. See
+    /// [`crate::plant::PlantComponent::render_actions`].
+    pub rng_seed: u64,
+}
+
+/// `egui::color::Hsva` isn't `Serialize`/`Deserialize`, so round-trip it
+/// through its `[h, s, v, a]` components instead.
+mod hsva_serde {
+    use bevy_egui::egui::color::Hsva;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(value: &Hsva, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        [value.h, value.s, value.v, value.a].serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Hsva, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let [h, s, v, a] = <[f32; 4]>::deserialize(deserializer)?;
+        Ok(Hsva::new(h, s, v, a))
+    }
 }
 
 impl Default for OptionsComponent {
@@ -310,6 +630,7 @@ impl Default for OptionsComponent {
             iterations: 6,
             line_width: 10f32,
             line_color: Hsva::from_rgb([0f32, 1f32, 0.1f32]),
+            rng_seed: 0,
         }
     }
 }